@@ -2,11 +2,11 @@
 //!
 //! 令 $G = (V, E)$ 为堆内存有向图，资源 T 占据子图 $S \subseteq G$。
 //!
-//! **不变量**：从 S 外部进入 S 的边恰好有一条 — Martyr 内的 `*mut T`。
+//! **不变量**：从 S 外部进入 S 的边恰好有一条 — Martyr 内的 `NonNull<T>`。
 //!
 //! ```text
-//! Martyr ──*mut T──→ [ T ──→ T.field ──→ ... ]
-//!          ↑          └──────── S ────────────┘
+//! Martyr ──NonNull<T>──→ [ T ──→ T.field ──→ ... ]
+//!          ↑              └──────── S ────────────┘
 //!       唯一边
 //! ```
 //!
@@ -18,9 +18,9 @@
 //! |------|------|
 //! | 引用逃逸 | HRTB `for<'a>` — 闭包无法捕获内部引用 |
 //! | 并发竞争 | `RwLock` 读写互斥 |
-//! | 悬垂指针 | `kill()` 先置空再释放 |
+//! | 悬垂指针 | 边为 `Option<NonNull<T>>`：`kill()` 先置 `None` 再释放，访问遇 `None` 即返回错误 |
 //! | 子图泄露 | T 的设计契约（计算理论边界） |
 
 mod martyr;
 
-pub use martyr::{Martyr, ResourceKilled};
+pub use martyr::{Guard, GuardMut, Martyr, ReplaceError, ResourceKilled};
@@ -1,94 +1,353 @@
-use parking_lot::RwLock;
-use std::ptr;
+use parking_lot::{
+    MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use std::{fmt, mem};
 
 /// 唯一边已被切断。资源子图不可达。
 #[derive(Debug, PartialEq, thiserror::Error)]
 #[error("resource killed")]
 pub struct ResourceKilled;
 
+/// `replace` 时唯一边已被切断。未能放入的新值原样交还调用方。
+#[derive(PartialEq, thiserror::Error)]
+#[error("resource killed")]
+pub struct ReplaceError<T>(pub T);
+
+impl<T> fmt::Debug for ReplaceError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ReplaceError").finish_non_exhaustive()
+    }
+}
+
 /// 唯一边守卫。
 ///
-/// `RwLock<*mut T>`：锁保护的就是唯一边本身。
-/// 非空 = 边存在 = 资源可达。空 = 边已切断 = 资源已回收。
-pub struct Martyr<T> {
-    edge: RwLock<*mut T>,
+/// `RwLock<Option<NonNull<T>>>`：锁保护的就是唯一边本身。
+/// `Some` = 边存在 = 资源可达。`None` = 边已切断 = 资源已回收。
+///
+/// `T` 可为 `?Sized`（如 `dyn Trait`），经 [`Martyr::from_box`] 建立。
+///
+/// # 不可重入
+///
+/// 访问期间（`invoke*` 闭包内、持有 [`Guard`]/[`GuardMut`] 时），对同一守卫：
+///
+/// - **安全**：`is_kill_requested` 与 `Debug`（不阻塞）；`try_kill`、`try_kill_for`、
+///   `try_invoke_for`、`try_invoke_mut_for`（非阻塞或限时，最坏返回 `None`）。
+///   其中 `try_kill`/`try_kill_for` 永远不会成功 — 自身持有的锁挡住了写锁，
+///   `try_kill_for` 必然耗尽整个 `timeout`。
+/// - **死锁**：`is_alive`、`invoke`、`invoke_mut`、`invoke_pair`、`guard`、`guard_mut`、
+///   `take`、`replace`、`kill`。它们排在自身持有的锁之后：独占访问中必然死锁，
+///   共享访问中一旦有 `kill` 排队即死锁。
+///
+/// 闭包内判断存活请轮询 `is_kill_requested`；需要同时读两处请用 [`Martyr::invoke_pair`]。
+pub struct Martyr<T: ?Sized> {
+    edge: RwLock<Option<NonNull<T>>>,
+    /// 正在等待写锁以切断边的调用数。仅作提示，不参与同步。
+    killers: AtomicUsize,
 }
 
 // SAFETY: Martyr 独占堆上的 T。T: Send — kill() 可在任意线程回收。
-unsafe impl<T: Send> Send for Martyr<T> {}
+unsafe impl<T: ?Sized + Send> Send for Martyr<T> {}
 // SAFETY: invoke() 并发时多线程共享 &T。T: Sync — &T 跨线程安全。
-unsafe impl<T: Send + Sync> Sync for Martyr<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for Martyr<T> {}
 
 impl<T> Martyr<T> {
     /// 将 T 移入堆，建立唯一边。
     ///
-    /// `Box::into_raw(Box::new(resource))` 创建系统中唯一的 `*mut T`。
+    /// `Box::new(resource)` 泄漏出的指针即系统中唯一指向 T 的边。
     pub fn new(resource: T) -> Self {
+        Self::from_box(Box::new(resource))
+    }
+
+    /// 切断唯一边，但不回收：将 T 按值交还调用方。
+    ///
+    /// 与 `kill()` 一样等待所有进行中的访问退出；之后守卫视资源为已死亡。
+    pub fn take(&self) -> Result<T, ResourceKilled> {
+        let ptr = self
+            .await_edge(RwLock::write)
+            .take()
+            .ok_or(ResourceKilled)?;
+        // SAFETY: ptr 来自 Box::leak。写锁保证独占，边已置空。
+        Ok(*unsafe { Box::from_raw(ptr.as_ptr()) })
+    }
+
+    /// 消费守卫，按值取回 T。
+    ///
+    /// 按值持有 → 无并发访问，无需加锁。共享的守卫先经 `Arc::into_inner` 确认唯一持有者。
+    pub fn into_inner(mut self) -> Result<T, ResourceKilled> {
+        let ptr = self.edge.get_mut().take().ok_or(ResourceKilled)?;
+        // SAFETY: ptr 来自 Box::leak。边已置空，Drop 不会再释放。
+        Ok(*unsafe { Box::from_raw(ptr.as_ptr()) })
+    }
+
+    /// 原位替换资源，唯一边保持不变。返回旧值。
+    ///
+    /// 写锁等待进行中的访问基于旧值完成；之后的访问看到新值。
+    /// 边已切断时返回 `Err(ReplaceError(new))` 交还新值 — 已死亡的资源不会复活。
+    pub fn replace(&self, new: T) -> Result<T, ReplaceError<T>> {
+        let edge = self.edge.write();
+        let Some(mut ptr) = *edge else {
+            return Err(ReplaceError(new));
+        };
+        // SAFETY: 边存在，写锁保证独占。
+        Ok(mem::replace(unsafe { ptr.as_mut() }, new))
+    }
+}
+
+impl<T: ?Sized> Martyr<T> {
+    /// 接管已装箱的资源，建立唯一边。`Box<dyn Trait>` 由此成为 `Martyr<dyn Trait>`。
+    pub fn from_box(resource: Box<T>) -> Self {
         Self {
-            edge: RwLock::new(Box::into_raw(Box::new(resource))),
+            edge: RwLock::new(Some(NonNull::from(Box::leak(resource)))),
+            killers: AtomicUsize::new(0),
         }
     }
 
     /// 切断唯一边。T 的子图成为孤立分量，立即回收。
     ///
     /// 先置空再释放（若 `T::drop` 恐慌，边已断，不会双重释放）。
+    ///
+    /// `parking_lot::RwLock` 为任务公平锁：kill 等待期间，新的 `invoke` 排在其后，
+    /// 只有进行中的访问需要退出。高频读流量不会饿死 kill。
     pub fn kill(&self) -> bool {
-        let mut edge = self.edge.write();
-        let ptr = *edge;
-        if ptr.is_null() {
+        Self::sever(self.await_edge(RwLock::write))
+    }
+
+    /// 非阻塞切断唯一边。
+    ///
+    /// 仍有进行中的访问则立即返回 `None`，资源保持存活 — "安全则杀，否则退让"。
+    pub fn try_kill(&self) -> Option<bool> {
+        self.edge.try_write().map(Self::sever)
+    }
+
+    /// 限时切断唯一边。
+    ///
+    /// 在 `timeout` 内等待所有进行中的 `invoke`/`invoke_mut` 退出后切断。
+    /// 超时返回 `None`，资源保持存活，由调用方决定重试或放弃。
+    /// 等待期间 [`Martyr::is_kill_requested`] 为真。
+    pub fn try_kill_for(&self, timeout: Duration) -> Option<bool> {
+        self.await_edge(|edge| edge.try_write_for(timeout))
+            .map(Self::sever)
+    }
+
+    /// 是否有 `kill`/`try_kill_for`/`take` 正在等待进行中的访问退出。
+    ///
+    /// 长时间运行的 `invoke` 闭包可轮询此信号并提前返回，把强制等待变为协作退出。
+    ///
+    /// 闭包内只轮询本方法：此刻 kill 已在排队，对同一守卫调用 `invoke`、`guard`、
+    /// `take` 等阻塞方法会排在它之后，而它又在等闭包返回 — 死锁。
+    ///
+    /// 信号只表示有切断调用在等待，不保证它能成功。访问内部对同一守卫调用
+    /// `try_kill_for` 注定超时失败，但计数仍持续整个 `timeout`，其间其他闭包会看到
+    /// 一次不会发生的 kill。锁无法区分持有者，此类提前退出只是多余，不影响正确性。
+    pub fn is_kill_requested(&self) -> bool {
+        self.killers.load(Ordering::Relaxed) != 0
+    }
+
+    /// 标记切断意图，再获取写锁。计数只覆盖等待阶段。
+    fn await_edge<'a, G>(&'a self, acquire: impl FnOnce(&'a RwLock<Option<NonNull<T>>>) -> G) -> G {
+        // Relaxed：计数只是给访问者的提示，互斥由写锁本身保证。
+        self.killers.fetch_add(1, Ordering::Relaxed);
+        let edge = acquire(&self.edge);
+        self.killers.fetch_sub(1, Ordering::Relaxed);
+        edge
+    }
+
+    fn sever(mut edge: RwLockWriteGuard<'_, Option<NonNull<T>>>) -> bool {
+        let Some(ptr) = edge.take() else {
             return false;
-        }
-        *edge = ptr::null_mut();
-        // SAFETY: ptr 来自 Box::into_raw，非空。写锁保证独占。
-        unsafe { drop(Box::from_raw(ptr)) };
+        };
+        // SAFETY: ptr 来自 Box::leak。写锁保证独占，边已置空。
+        unsafe { drop(Box::from_raw(ptr.as_ptr())) };
         true
     }
 
     /// 边是否存在。
     pub fn is_alive(&self) -> bool {
-        !self.edge.read().is_null()
+        self.edge.read().is_some()
     }
 
     /// 通过唯一边共享访问。读锁允许并发。
     ///
     /// HRTB `for<'a> FnOnce(&'a T) -> R` 确保 `&T` 无法逃逸。
+    ///
+    /// 闭包内对同一守卫的调用受 [`Martyr`] 的「不可重入」规则约束。
     pub fn invoke<F, R>(&self, f: F) -> Result<R, ResourceKilled>
     where
         F: for<'a> FnOnce(&'a T) -> R,
     {
         let edge = self.edge.read();
-        let ptr = *edge;
-        if ptr.is_null() {
-            return Err(ResourceKilled);
-        }
-        // SAFETY: ptr 非空，读锁阻止并发 kill/invoke_mut。
-        Ok(f(unsafe { &*ptr }))
+        let ptr = edge.ok_or(ResourceKilled)?;
+        // SAFETY: 边存在，读锁阻止并发 kill/invoke_mut。
+        Ok(f(unsafe { ptr.as_ref() }))
     }
 
     /// 通过唯一边独占访问。写锁保证互斥。
     ///
     /// HRTB `for<'a> FnOnce(&'a mut T) -> R` 确保 `&mut T` 无法逃逸。
+    ///
+    /// 闭包内对同一守卫的调用受 [`Martyr`] 的「不可重入」规则约束。
     pub fn invoke_mut<F, R>(&self, f: F) -> Result<R, ResourceKilled>
     where
         F: for<'a> FnOnce(&'a mut T) -> R,
     {
         let edge = self.edge.write();
-        let ptr = *edge;
-        if ptr.is_null() {
+        let mut ptr = edge.ok_or(ResourceKilled)?;
+        // SAFETY: 边存在，写锁保证独占。
+        Ok(f(unsafe { ptr.as_mut() }))
+    }
+
+    /// 同时通过两条唯一边共享访问。任一资源已死亡则整体拒绝。
+    ///
+    /// 两把读锁按地址顺序获取：与其他 `invoke_pair` 调用之间不会因加锁顺序死锁。
+    /// 手写的反序嵌套 `invoke` 不受此保护，在两条边都有 kill 排队时仍可能死锁。
+    pub fn invoke_pair<U, F, R>(&self, other: &Martyr<U>, f: F) -> Result<R, ResourceKilled>
+    where
+        U: ?Sized,
+        F: for<'a, 'b> FnOnce(&'a T, &'b U) -> R,
+    {
+        let this = self as *const Self as *const () as usize;
+        let that = other as *const Martyr<U> as *const () as usize;
+        let (a, b) = if this == that {
+            // 同一守卫：第二次加锁必须递归，否则排队的 kill 会与自身死锁。
+            let a = self.edge.read();
+            (a, other.edge.read_recursive())
+        } else if this < that {
+            let a = self.edge.read();
+            (a, other.edge.read())
+        } else {
+            let b = other.edge.read();
+            (self.edge.read(), b)
+        };
+        let (Some(a), Some(b)) = (*a, *b) else {
             return Err(ResourceKilled);
-        }
-        // SAFETY: ptr 非空，写锁保证独占。
-        Ok(f(unsafe { &mut *ptr }))
+        };
+        // SAFETY: 两条边均存在，读锁阻止并发 kill/invoke_mut。
+        Ok(f(unsafe { a.as_ref() }, unsafe { b.as_ref() }))
+    }
+
+    /// 限时共享访问。`timeout` 内拿不到读锁（如 `kill()`/`invoke_mut` 占用）则返回 `None`。
+    pub fn try_invoke_for<F, R>(&self, timeout: Duration, f: F) -> Option<Result<R, ResourceKilled>>
+    where
+        F: for<'a> FnOnce(&'a T) -> R,
+    {
+        let edge = self.edge.try_read_for(timeout)?;
+        let Some(ptr) = *edge else {
+            return Some(Err(ResourceKilled));
+        };
+        // SAFETY: 边存在，读锁阻止并发 kill/invoke_mut。
+        Some(Ok(f(unsafe { ptr.as_ref() })))
+    }
+
+    /// 限时独占访问。`timeout` 内拿不到写锁则返回 `None`。
+    pub fn try_invoke_mut_for<F, R>(
+        &self,
+        timeout: Duration,
+        f: F,
+    ) -> Option<Result<R, ResourceKilled>>
+    where
+        F: for<'a> FnOnce(&'a mut T) -> R,
+    {
+        let edge = self.edge.try_write_for(timeout)?;
+        let Some(mut ptr) = *edge else {
+            return Some(Err(ResourceKilled));
+        };
+        // SAFETY: 边存在，写锁保证独占。
+        Some(Ok(f(unsafe { ptr.as_mut() })))
+    }
+
+    /// 以守卫形式共享访问。守卫存活期间持有读锁。
+    ///
+    /// `&T` 的生命周期受守卫借用约束，无法逃逸；守卫未释放前 `kill()` 阻塞。
+    pub fn guard(&self) -> Result<Guard<'_, T>, ResourceKilled> {
+        RwLockReadGuard::try_map(self.edge.read(), |edge| {
+            // SAFETY: 边存在则指向存活的 T，读锁阻止并发 kill/invoke_mut。
+            edge.as_ref().map(|ptr| unsafe { ptr.as_ref() })
+        })
+        .map(Guard)
+        .map_err(|_| ResourceKilled)
+    }
+
+    /// 以守卫形式独占访问。守卫存活期间持有写锁。
+    pub fn guard_mut(&self) -> Result<GuardMut<'_, T>, ResourceKilled> {
+        RwLockWriteGuard::try_map(self.edge.write(), |edge| {
+            // SAFETY: 边存在则指向存活的 T，写锁保证独占。
+            edge.as_mut().map(|ptr| unsafe { ptr.as_mut() })
+        })
+        .map(GuardMut)
+        .map_err(|_| ResourceKilled)
+    }
+}
+
+/// 共享访问守卫。存活期间持有读锁，解引用为 `&T`。
+pub struct Guard<'a, T: ?Sized>(MappedRwLockReadGuard<'a, T>);
+
+impl<T: ?Sized> Deref for Guard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Guard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// 独占访问守卫。存活期间持有写锁，解引用为 `&mut T`。
+pub struct GuardMut<'a, T: ?Sized>(MappedRwLockWriteGuard<'a, T>);
+
+impl<T: ?Sized> Deref for GuardMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for GuardMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for GuardMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// 非阻塞快照，不会因调试输出卡住进程。
+///
+/// 拿不到读锁时：有 kill 排队显示 `<kill pending>`（公平锁下读者排在它之后），
+/// 否则为写者占用或排队（`invoke_mut`/`guard_mut`/`replace` 等），显示 `<locked>`。
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Martyr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 只读一次，`<kill pending>` 与 `kill_requested` 字段保持一致。
+        let kill_requested = self.is_kill_requested();
+        let mut d = f.debug_struct("Martyr");
+        match self.edge.try_read().as_deref() {
+            // SAFETY: 边存在则指向存活的 T，读锁阻止并发 kill/invoke_mut。
+            Some(Some(ptr)) => d.field("resource", &unsafe { ptr.as_ref() }),
+            Some(None) => d.field("resource", &format_args!("<killed>")),
+            None if kill_requested => d.field("resource", &format_args!("<kill pending>")),
+            None => d.field("resource", &format_args!("<locked>")),
+        };
+        d.field("kill_requested", &kill_requested).finish()
     }
 }
 
-impl<T> Drop for Martyr<T> {
+impl<T: ?Sized> Drop for Martyr<T> {
     fn drop(&mut self) {
         // &mut self → 无其他引用 → get_mut 无需加锁
-        let ptr = *self.edge.get_mut();
-        if !ptr.is_null() {
-            // SAFETY: ptr 来自 Box::into_raw，非空。
-            unsafe { drop(Box::from_raw(ptr)) };
+        if let Some(ptr) = self.edge.get_mut().take() {
+            // SAFETY: ptr 来自 Box::leak，边已置空。
+            unsafe { drop(Box::from_raw(ptr.as_ptr())) };
         }
     }
 }
@@ -98,6 +357,7 @@ mod tests {
     use super::*;
     use std::sync::Arc;
 
+    #[derive(Debug)]
     struct Counter(i32);
 
     impl Counter {
@@ -122,6 +382,103 @@ mod tests {
         assert_eq!(m.invoke(|c| c.get()), Ok(1));
     }
 
+    #[test]
+    fn guards_give_scoped_access() {
+        let m = Martyr::new(Counter(0));
+        m.guard_mut().unwrap().increment();
+        assert_eq!(m.guard().unwrap().get(), 1);
+        assert_eq!(format!("{:?}", m.guard().unwrap()), "Counter(1)");
+        assert_eq!(format!("{:?}", m.guard_mut().unwrap()), "Counter(1)");
+        m.kill();
+        assert!(m.guard().is_err());
+        assert!(m.guard_mut().is_err());
+    }
+
+    #[test]
+    fn invoke_pair_sees_both_or_neither() {
+        let a = Martyr::new(Counter(1));
+        let b = Martyr::new(Counter(2));
+        assert_eq!(a.invoke_pair(&b, |x, y| x.get() + y.get()), Ok(3));
+        assert_eq!(b.invoke_pair(&a, |x, y| x.get() - y.get()), Ok(1));
+        assert_eq!(a.invoke_pair(&a, |x, y| x.get() + y.get()), Ok(2));
+        b.kill();
+        assert_eq!(
+            a.invoke_pair(&b, |x, y| x.get() + y.get()),
+            Err(ResourceKilled)
+        );
+    }
+
+    #[test]
+    fn unsized_resources_via_from_box() {
+        let f: Martyr<dyn Fn(i32) -> i32 + Send + Sync> = Martyr::from_box(Box::new(|x| x + 1));
+        assert_eq!(f.invoke(|f| f(1)), Ok(2));
+        assert!(f.kill());
+        assert!(f.invoke(|f| f(1)).is_err());
+
+        let s: Martyr<[i32]> = Martyr::from_box(vec![1, 2, 3].into_boxed_slice());
+        s.invoke_mut(|s| s[0] = 9).unwrap();
+        assert_eq!(&*s.guard().unwrap(), &[9, 2, 3]);
+        assert_eq!(
+            format!("{s:?}"),
+            "Martyr { resource: [9, 2, 3], kill_requested: false }"
+        );
+    }
+
+    #[test]
+    fn invoke_pair_opposite_orders_under_pending_kills() {
+        use std::time::Instant;
+        let a = Arc::new(Martyr::new(Counter(1)));
+        let b = Arc::new(Martyr::new(Counter(2)));
+        let (lo, hi) = if Arc::as_ptr(&a) < Arc::as_ptr(&b) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let spawn_pair = |x: &Arc<Martyr<Counter>>, y: &Arc<Martyr<Counter>>| {
+            let (x, y) = (Arc::clone(x), Arc::clone(y));
+            std::thread::spawn(move || x.invoke_pair(&y, |x, y| x.get() + y.get()))
+        };
+        let spawn_kill = |m: &Arc<Martyr<Counter>>| {
+            let m = Arc::clone(m);
+            std::thread::spawn(move || m.kill())
+        };
+        // `<kill pending>` 出现即 kill 已在锁上排队，新读者会被挡住。
+        let wait_pending = |m: &Martyr<Counter>| {
+            while !format!("{m:?}").contains("<kill pending>") {
+                std::thread::yield_now();
+            }
+        };
+
+        let held = hi.guard().unwrap();
+        let kill_hi = spawn_kill(&hi);
+        wait_pending(&hi);
+
+        // 反序调用必须先锁低地址的 lo，再阻塞在 hi 排队的 kill 之后。
+        let reversed = spawn_pair(&hi, &lo);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while lo
+            .try_invoke_mut_for(Duration::from_millis(1), |_| ())
+            .is_some()
+        {
+            assert!(
+                Instant::now() < deadline,
+                "reversed invoke_pair never held lo"
+            );
+            std::thread::yield_now();
+        }
+
+        // 两条边都有 kill 排队；正序调用排在 lo 的 kill 之后。
+        let kill_lo = spawn_kill(&lo);
+        wait_pending(&lo);
+        let forward = spawn_pair(&lo, &hi);
+
+        drop(held);
+        assert_eq!(reversed.join().unwrap(), Err(ResourceKilled));
+        assert_eq!(forward.join().unwrap(), Err(ResourceKilled));
+        assert!(kill_hi.join().unwrap());
+        assert!(kill_lo.join().unwrap());
+    }
+
     #[test]
     fn kill_severs_the_edge() {
         let m = Martyr::new(Counter(42));
@@ -132,6 +489,41 @@ mod tests {
         assert!(!m.kill());
     }
 
+    #[test]
+    fn take_returns_resource_by_value() {
+        let m = Martyr::new(Counter(42));
+        let c = m.take().unwrap();
+        assert_eq!(c.get(), 42);
+        assert!(!m.is_alive());
+        assert!(m.take().is_err());
+        assert!(!m.kill());
+    }
+
+    #[test]
+    fn into_inner_recovers_sole_resource() {
+        let m = Arc::new(Martyr::new(Counter(42)));
+        let m2 = Arc::clone(&m);
+        assert!(Arc::try_unwrap(m2).is_err());
+        let c = Arc::into_inner(m).unwrap().into_inner().unwrap();
+        assert_eq!(c.get(), 42);
+
+        let killed = Martyr::new(Counter(0));
+        killed.kill();
+        assert!(killed.into_inner().is_err());
+    }
+
+    #[test]
+    fn replace_swaps_behind_the_same_edge() {
+        let m = Martyr::new(Counter(1));
+        let old = m.replace(Counter(2)).unwrap();
+        assert_eq!(old.get(), 1);
+        assert_eq!(m.invoke(|c| c.get()), Ok(2));
+        m.kill();
+        let ReplaceError(rejected) = m.replace(Counter(3)).unwrap_err();
+        assert_eq!(rejected.get(), 3);
+        assert!(!m.is_alive());
+    }
+
     #[test]
     fn arc_sharing_single_edge() {
         let m = Arc::new(Martyr::new(Counter(42)));
@@ -161,6 +553,142 @@ mod tests {
         assert_eq!(m.invoke(|c| c.get()), Ok(1000));
     }
 
+    #[test]
+    fn try_kill_backs_off_while_invoked() {
+        let m = Martyr::new(Counter(42));
+        {
+            let _held = m.guard().unwrap();
+            assert_eq!(m.try_kill(), None);
+            assert!(m.is_alive());
+        }
+        assert_eq!(m.try_kill(), Some(true));
+        assert_eq!(m.try_kill(), Some(false));
+    }
+
+    #[test]
+    fn try_kill_for_times_out_while_invoked() {
+        use std::sync::mpsc;
+        let m = Arc::new(Martyr::new(Counter(42)));
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let m2 = Arc::clone(&m);
+        let h = std::thread::spawn(move || {
+            m2.invoke(|_| {
+                entered_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+            })
+        });
+        entered_rx.recv().unwrap();
+        assert_eq!(m.try_kill_for(Duration::from_millis(10)), None);
+        assert!(m.is_alive());
+        release_tx.send(()).unwrap();
+        h.join().unwrap().unwrap();
+        assert_eq!(m.try_kill_for(Duration::from_millis(10)), Some(true));
+        assert_eq!(m.try_kill_for(Duration::from_millis(10)), Some(false));
+    }
+
+    #[test]
+    fn try_invoke_for_times_out_while_locked() {
+        let m = Martyr::new(Counter(0));
+        let timeout = Duration::from_millis(10);
+        {
+            let _held = m.guard_mut().unwrap();
+            assert!(m.try_invoke_for(timeout, |c| c.get()).is_none());
+            assert!(m.try_invoke_mut_for(timeout, |c| c.increment()).is_none());
+        }
+        assert_eq!(
+            m.try_invoke_mut_for(timeout, |c| c.increment()),
+            Some(Ok(()))
+        );
+        assert_eq!(m.try_invoke_for(timeout, |c| c.get()), Some(Ok(1)));
+        m.kill();
+        assert_eq!(
+            m.try_invoke_for(timeout, |c| c.get()),
+            Some(Err(ResourceKilled))
+        );
+    }
+
+    #[test]
+    fn invoke_observes_pending_kill() {
+        use std::sync::mpsc;
+        let m = Arc::new(Martyr::new(Counter(42)));
+        assert!(!m.is_kill_requested());
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let m2 = Arc::clone(&m);
+        let h = std::thread::spawn(move || {
+            m2.invoke(|_| {
+                entered_tx.send(()).unwrap();
+                while !m2.is_kill_requested() {
+                    std::thread::yield_now();
+                }
+            })
+        });
+        entered_rx.recv().unwrap();
+        assert!(m.kill());
+        assert_eq!(h.join().unwrap(), Ok(()));
+        assert!(!m.is_kill_requested());
+    }
+
+    #[test]
+    fn debug_reports_edge_state() {
+        let m = Martyr::new(Counter(42));
+        assert_eq!(
+            format!("{m:?}"),
+            "Martyr { resource: Counter(42), kill_requested: false }"
+        );
+        {
+            let _held = m.guard_mut().unwrap();
+            assert_eq!(
+                format!("{m:?}"),
+                "Martyr { resource: <locked>, kill_requested: false }"
+            );
+        }
+        let m = Arc::new(m);
+        let held = m.guard().unwrap();
+        let killer = {
+            let m = Arc::clone(&m);
+            std::thread::spawn(move || m.kill())
+        };
+        while !m.is_kill_requested() {
+            std::thread::yield_now();
+        }
+        // 计数先于写者入队：等待 try_read 真正被排队的 kill 挡住。
+        loop {
+            let s = format!("{m:?}");
+            if s != "Martyr { resource: Counter(42), kill_requested: true }" {
+                assert_eq!(
+                    s,
+                    "Martyr { resource: <kill pending>, kill_requested: true }"
+                );
+                break;
+            }
+            std::thread::yield_now();
+        }
+        drop(held);
+        assert!(killer.join().unwrap());
+        assert_eq!(
+            format!("{m:?}"),
+            "Martyr { resource: <killed>, kill_requested: false }"
+        );
+    }
+
+    #[test]
+    fn try_calls_inside_invoke_are_bounded() {
+        let m = Martyr::new(Counter(42));
+        let timeout = Duration::from_millis(10);
+        let r = m.invoke(|_| {
+            assert_eq!(m.try_kill(), None);
+            assert_eq!(m.try_kill_for(timeout), None);
+            assert!(m.try_invoke_mut_for(timeout, |c| c.increment()).is_none());
+            assert_eq!(
+                format!("{m:?}"),
+                "Martyr { resource: Counter(42), kill_requested: false }"
+            );
+        });
+        assert_eq!(r, Ok(()));
+        assert!(m.is_alive());
+    }
+
     /// 验证 kill 确实回收资源（T::drop 被调用）。
     #[test]
     fn kill_reclaims_resource() {